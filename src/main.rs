@@ -1,18 +1,71 @@
 use std::fs;
+use std::future::Future;
 use std::io::{self, Write};
 use std::path::PathBuf;
 use std::process;
+use std::time::Duration;
 
 use clap::{Parser, Subcommand, ValueEnum};
 use rspotify::{
-    AuthCodeSpotify, Config as SpotifyConfig, Credentials, OAuth,
-    model::{AlbumId, PlaylistId, SearchResult, SearchType, TrackId, UserId},
+    AuthCodeSpotify, ClientError, ClientResult, Config as SpotifyConfig, Credentials, OAuth,
+    model::{AlbumId, ArtistId, PlaylistId, SearchResult, SearchType, TrackId, UserId},
     prelude::*,
     scopes,
 };
 use serde::Deserialize;
 use serde_json::{Value, json};
 
+// ---------------------------------------------------------------------------
+// Errors
+// ---------------------------------------------------------------------------
+
+/// Everything that can go wrong while running a command.
+///
+/// Commands return `Result<(), SporesError>`; `main` renders the failure as either a plain
+/// `error: …` line or, under `--json`, an `{"error": …}` object, and exits non-zero.
+#[derive(Debug)]
+enum SporesError {
+    /// A call to the Spotify Web API failed.
+    Client(ClientError),
+    /// A track/album/playlist id or URI could not be parsed.
+    Id(rspotify::model::IdError),
+    /// Reading or writing a local file failed.
+    Io(std::io::Error),
+    /// Invalid user input, such as a malformed or mismatched share link.
+    Input(String),
+}
+
+impl std::fmt::Display for SporesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SporesError::Client(e) => write!(f, "Spotify API error: {e}"),
+            SporesError::Id(e) => write!(f, "invalid Spotify id: {e}"),
+            SporesError::Io(e) => write!(f, "{e}"),
+            SporesError::Input(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for SporesError {}
+
+impl From<ClientError> for SporesError {
+    fn from(e: ClientError) -> Self {
+        SporesError::Client(e)
+    }
+}
+
+impl From<rspotify::model::IdError> for SporesError {
+    fn from(e: rspotify::model::IdError) -> Self {
+        SporesError::Id(e)
+    }
+}
+
+impl From<std::io::Error> for SporesError {
+    fn from(e: std::io::Error) -> Self {
+        SporesError::Io(e)
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Config
 // ---------------------------------------------------------------------------
@@ -158,6 +211,10 @@ redirect_uri = "{redirect_uri}"
 #[derive(Parser)]
 #[command(name = "spores", about = "Spotify playlist manager")]
 struct Cli {
+    /// Emit errors as a JSON `{"error": …}` object instead of a plain message
+    #[arg(long, global = true)]
+    json: bool,
+
     #[command(subcommand)]
     command: Command,
 }
@@ -197,6 +254,39 @@ enum Command {
         #[arg(required = true)]
         ids: Vec<String>,
     },
+
+    /// Remove a track, album, or playlist from your library
+    Unsave {
+        /// Type of item to remove
+        #[arg(short, long, value_enum, default_value_t = ItemType::Track)]
+        r#type: ItemType,
+
+        /// IDs or URIs of items to remove
+        #[arg(required = true)]
+        ids: Vec<String>,
+    },
+
+    /// Follow an artist, user, or playlist
+    Follow {
+        /// Type of item to follow
+        #[arg(short, long, value_enum, default_value_t = FollowType::Artist)]
+        r#type: FollowType,
+
+        /// IDs or URIs of items to follow
+        #[arg(required = true)]
+        ids: Vec<String>,
+    },
+
+    /// Unfollow an artist, user, or playlist
+    Unfollow {
+        /// Type of item to unfollow
+        #[arg(short, long, value_enum, default_value_t = FollowType::Artist)]
+        r#type: FollowType,
+
+        /// IDs or URIs of items to unfollow
+        #[arg(required = true)]
+        ids: Vec<String>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -222,6 +312,14 @@ enum PlaylistCommand {
     Info {
         /// Playlist ID or URI
         playlist: String,
+
+        /// Fetch a single page of this many tracks instead of the whole playlist
+        #[arg(short, long)]
+        limit: Option<u32>,
+
+        /// Offset of the first track to fetch (implies manual paging)
+        #[arg(short, long)]
+        offset: Option<u32>,
     },
 
     /// Add tracks to a playlist
@@ -233,6 +331,59 @@ enum PlaylistCommand {
         #[arg(required = true)]
         tracks: Vec<String>,
     },
+
+    /// Apply a set operation across two or more playlists
+    Compare {
+        /// Playlist IDs or URIs to compare (two or more)
+        #[arg(required = true, num_args = 2..)]
+        playlists: Vec<String>,
+
+        /// Set operation to apply across the playlists
+        #[arg(long, value_enum, default_value_t = SetOp::Intersect)]
+        op: SetOp,
+
+        /// Materialize the result into a new private playlist with this name
+        #[arg(long)]
+        into: Option<String>,
+    },
+
+    /// Remove tracks from a playlist
+    Remove {
+        /// Playlist ID or URI
+        playlist: String,
+
+        /// Track IDs or URIs to remove (all occurrences)
+        #[arg(required = true)]
+        tracks: Vec<String>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum SetOp {
+    /// Tracks present in every playlist
+    Intersect,
+    /// Tracks present in any playlist
+    Union,
+    /// Tracks in the first playlist that are in none of the others
+    Difference,
+}
+
+#[derive(Clone, ValueEnum)]
+enum FollowType {
+    Artist,
+    User,
+    Playlist,
+}
+
+impl FollowType {
+    /// The path segment Spotify uses for this follow target in `open.spotify.com` links.
+    fn as_url_segment(&self) -> &'static str {
+        match self {
+            FollowType::Artist => "artist",
+            FollowType::User => "user",
+            FollowType::Playlist => "playlist",
+        }
+    }
 }
 
 #[derive(Clone, ValueEnum)]
@@ -252,6 +403,16 @@ impl ItemType {
             ItemType::Playlist => SearchType::Playlist,
         }
     }
+
+    /// The path segment Spotify uses for this item type in `open.spotify.com` links.
+    fn as_url_segment(&self) -> &'static str {
+        match self {
+            ItemType::Track => "track",
+            ItemType::Album => "album",
+            ItemType::Artist => "artist",
+            ItemType::Playlist => "playlist",
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -262,11 +423,159 @@ fn print_json(value: &Value) {
     println!("{}", serde_json::to_string_pretty(value).unwrap());
 }
 
+/// Normalize a user-supplied item reference into a form rspotify's `from_id_or_uri`
+/// accepts.
+///
+/// Bare IDs and `spotify:` URIs pass through unchanged. Full share links copied from the
+/// Spotify app — `https://open.spotify.com/<type>/<id>?si=…`, optionally with an `intl-*`
+/// locale prefix — have their query string stripped and are rewritten to a canonical
+/// `spotify:<type>:<id>` URI. The link's type segment is always checked against
+/// `expected`, so a playlist link handed to a `--type track` command fails with a clear
+/// message rather than a confusing API error.
+fn normalize_ref(input: &str, expected: &str) -> Result<String, SporesError> {
+    let rest = match input
+        .strip_prefix("https://open.spotify.com/")
+        .or_else(|| input.strip_prefix("http://open.spotify.com/"))
+        .or_else(|| input.strip_prefix("open.spotify.com/"))
+    {
+        Some(rest) => rest,
+        // Not a share URL — hand bare IDs / spotify: URIs straight through.
+        None => return Ok(input.to_string()),
+    };
+
+    let path = rest.split(['?', '#']).next().unwrap_or(rest);
+    let mut segments = path.split('/').filter(|s| !s.is_empty());
+
+    // Skip an optional locale prefix such as `intl-de`.
+    let mut kind = segments.next().unwrap_or("");
+    if kind.starts_with("intl-") {
+        kind = segments.next().unwrap_or("");
+    }
+    let id = segments.next().unwrap_or("");
+
+    if kind.is_empty() || id.is_empty() {
+        return Err(SporesError::Input(format!("malformed Spotify URL: {input}")));
+    }
+
+    if kind != expected {
+        return Err(SporesError::Input(format!(
+            "expected a {expected} link but got a {kind} link: {input}"
+        )));
+    }
+
+    Ok(format!("spotify:{kind}:{id}"))
+}
+
+// ---------------------------------------------------------------------------
+// Retry
+// ---------------------------------------------------------------------------
+
+/// Maximum number of attempts (including the first) before a call is allowed to fail.
+const MAX_RETRIES: u32 = 5;
+
+/// Fallback delay when Spotify rate-limits us without a `Retry-After` header.
+const DEFAULT_RETRY_AFTER: u64 = 5;
+
+/// Largest backoff we will wait for a transient network/5xx error.
+const MAX_BACKOFF_SECS: u64 = 32;
+
+/// Run a rspotify API call, transparently retrying on rate limits and transient failures.
+///
+/// On an HTTP 429 the `Retry-After` header (in seconds) is honoured, falling back to
+/// [`DEFAULT_RETRY_AFTER`] when it is absent. Connection errors and 5xx responses are
+/// retried with exponential backoff (1s, 2s, 4s, …) capped at [`MAX_BACKOFF_SECS`], up to
+/// [`MAX_RETRIES`] attempts; the underlying error is surfaced once retries are exhausted.
+async fn with_retry<T, F, Fut>(mut f: F) -> ClientResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ClientResult<T>>,
+{
+    let mut attempt = 0u32;
+    loop {
+        match f().await {
+            Ok(value) => return Ok(value),
+            Err(err) => {
+                attempt += 1;
+                match retry_delay(&err, attempt) {
+                    Some(delay) if attempt < MAX_RETRIES => {
+                        tokio::time::sleep(delay).await;
+                    }
+                    _ => return Err(err),
+                }
+            }
+        }
+    }
+}
+
+/// Decide whether (and for how long) a failed call should be retried.
+///
+/// Returns `None` for errors that are not worth retrying (bad IDs, auth failures, parse
+/// errors, 4xx other than 429), in which case the error is surfaced immediately.
+fn retry_delay(err: &ClientError, attempt: u32) -> Option<Duration> {
+    match err {
+        ClientError::Http(http_err) => match http_err.as_ref() {
+            rspotify::http::HttpError::StatusCode(resp) => {
+                let status = resp.status().as_u16();
+                if status == 429 {
+                    let secs = resp
+                        .headers()
+                        .get("retry-after")
+                        .and_then(|v| v.to_str().ok())
+                        .and_then(|v| v.parse::<u64>().ok())
+                        .unwrap_or(DEFAULT_RETRY_AFTER);
+                    Some(Duration::from_secs(secs))
+                } else if (500..600).contains(&status) {
+                    Some(backoff(attempt))
+                } else {
+                    None
+                }
+            }
+            // Connection-level errors (timeouts, resets) are worth another attempt.
+            rspotify::http::HttpError::Client(_) => Some(backoff(attempt)),
+        },
+        _ => None,
+    }
+}
+
+/// Exponential backoff: 1s, 2s, 4s, … capped at [`MAX_BACKOFF_SECS`].
+fn backoff(attempt: u32) -> Duration {
+    let secs = 2u64.saturating_pow(attempt - 1).min(MAX_BACKOFF_SECS);
+    Duration::from_secs(secs)
+}
+
+// ---------------------------------------------------------------------------
+// Pagination
+// ---------------------------------------------------------------------------
+
+/// Walk a paginated rspotify endpoint, collecting every item across all pages.
+///
+/// `fetch` is invoked with `(limit, offset)` and must return a single [`Page`]; pages are
+/// requested with a window of `page_size` until `next` is `None`. Callers route the inner
+/// call through [`with_retry`] so pagination survives rate limits.
+async fn collect_pages<T, F, Fut>(page_size: u32, mut fetch: F) -> ClientResult<Vec<T>>
+where
+    F: FnMut(u32, u32) -> Fut,
+    Fut: Future<Output = ClientResult<rspotify::model::Page<T>>>,
+{
+    let mut items = Vec::new();
+    let mut offset = 0u32;
+    loop {
+        let page = fetch(page_size, offset).await?;
+        let is_last = page.next.is_none();
+        items.extend(page.items);
+        if is_last {
+            break;
+        }
+        offset += page_size;
+    }
+    Ok(items)
+}
+
 // ---------------------------------------------------------------------------
 // Auth
 // ---------------------------------------------------------------------------
 
-async fn authenticate() -> AuthCodeSpotify {
+async fn authenticate() -> Result<AuthCodeSpotify, SporesError> {
     let app = load_config();
 
     let creds = Credentials::new(&app.client_id, &app.client_secret);
@@ -282,7 +591,9 @@ async fn authenticate() -> AuthCodeSpotify {
             "playlist-read-collaborative",
             "playlist-modify-public",
             "playlist-modify-private",
-            "user-library-modify"
+            "user-library-modify",
+            "user-follow-modify",
+            "user-follow-read"
         ),
         ..Default::default()
     };
@@ -296,21 +607,24 @@ async fn authenticate() -> AuthCodeSpotify {
     };
 
     let spotify = AuthCodeSpotify::with_config(creds, oauth, config);
-    let url = spotify.get_authorize_url(false).unwrap();
-    spotify.prompt_for_token(&url).await.unwrap();
-    spotify
+    let url = spotify.get_authorize_url(false)?;
+    spotify.prompt_for_token(&url).await?;
+    Ok(spotify)
 }
 
 // ---------------------------------------------------------------------------
 // Search
 // ---------------------------------------------------------------------------
 
-async fn cmd_search(spotify: &AuthCodeSpotify, query: &str, item_type: &ItemType, limit: u32) {
+async fn cmd_search(
+    spotify: &AuthCodeSpotify,
+    query: &str,
+    item_type: &ItemType,
+    limit: u32,
+) -> Result<(), SporesError> {
     let search_type = item_type.to_search_type();
-    let result = spotify
-        .search(query, search_type, None, None, Some(limit), None)
-        .await
-        .unwrap();
+    let result = with_retry(|| spotify.search(query, search_type, None, None, Some(limit), None))
+        .await?;
 
     match result {
         SearchResult::Tracks(page) => {
@@ -408,45 +722,40 @@ async fn cmd_search(spotify: &AuthCodeSpotify, query: &str, item_type: &ItemType
             print_json(&json!({ "error": "unsupported search type" }));
         }
     }
+
+    Ok(())
 }
 
 // ---------------------------------------------------------------------------
 // Playlist commands
 // ---------------------------------------------------------------------------
 
-async fn cmd_playlist_list(spotify: &AuthCodeSpotify) {
-    let mut playlists: Vec<Value> = Vec::new();
-    let mut offset = 0u32;
-    let limit = 50u32;
-
-    loop {
-        let page = spotify
-            .current_user_playlists_manual(Some(limit), Some(offset))
-            .await
-            .unwrap();
+async fn cmd_playlist_list(spotify: &AuthCodeSpotify) -> Result<(), SporesError> {
+    let items = collect_pages(50, |limit, offset| {
+        with_retry(move || spotify.current_user_playlists_manual(Some(limit), Some(offset)))
+    })
+    .await?;
 
-        for playlist in &page.items {
-            playlists.push(json!({
+    let playlists: Vec<Value> = items
+        .iter()
+        .map(|playlist| {
+            json!({
                 "id": playlist.id.to_string(),
                 "name": playlist.name,
                 "tracks": playlist.tracks.total,
                 "public": playlist.public.unwrap_or(false),
                 "owner": playlist.owner.display_name.as_deref().unwrap_or("unknown"),
                 "url": playlist.external_urls.get("spotify"),
-            }));
-        }
-
-        if page.next.is_none() {
-            break;
-        }
-
-        offset += limit;
-    }
+            })
+        })
+        .collect();
 
     print_json(&json!({
         "total": playlists.len(),
         "playlists": playlists,
     }));
+
+    Ok(())
 }
 
 async fn cmd_playlist_create(
@@ -454,14 +763,14 @@ async fn cmd_playlist_create(
     name: &str,
     public: bool,
     description: Option<&str>,
-) {
-    let user = spotify.current_user().await.unwrap();
-    let user_id = UserId::from_id(user.id.id()).unwrap();
+) -> Result<(), SporesError> {
+    let user = with_retry(|| spotify.current_user()).await?;
+    let user_id = UserId::from_id(user.id.id())?;
 
-    let playlist = spotify
-        .user_playlist_create(user_id, name, Some(public), None, description)
-        .await
-        .unwrap();
+    let playlist = with_retry(|| {
+        spotify.user_playlist_create(user_id.clone(), name, Some(public), None, description)
+    })
+    .await?;
 
     print_json(&json!({
         "id": playlist.id.to_string(),
@@ -470,30 +779,48 @@ async fn cmd_playlist_create(
         "description": playlist.description,
         "url": playlist.external_urls.get("spotify"),
     }));
+
+    Ok(())
 }
 
-async fn cmd_playlist_info(spotify: &AuthCodeSpotify, playlist_id_str: &str) {
-    let playlist_id = PlaylistId::from_id_or_uri(playlist_id_str).unwrap();
-    let playlist = spotify.playlist(playlist_id, None, None).await.unwrap();
+async fn cmd_playlist_info(
+    spotify: &AuthCodeSpotify,
+    playlist_id_str: &str,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> Result<(), SporesError> {
+    let playlist_ref = normalize_ref(playlist_id_str, "playlist")?;
+    let playlist_id = PlaylistId::from_id_or_uri(&playlist_ref)?;
+    let playlist = with_retry(|| spotify.playlist(playlist_id.clone(), None, None)).await?;
+
+    // `playlist()` only returns the first page of tracks, so walk the full track list
+    // separately. When the caller asks for a specific `--limit`/`--offset` we fetch just
+    // that page instead of the whole playlist.
+    let items = if limit.is_some() || offset.is_some() {
+        let page = with_retry(|| {
+            spotify.playlist_items_manual(
+                playlist_id.clone(),
+                None,
+                None,
+                limit.or(Some(100)),
+                offset.or(Some(0)),
+            )
+        })
+        .await?;
+        page.items
+    } else {
+        collect_pages(100, |lim, off| {
+            let pid = playlist_id.clone();
+            with_retry(move || spotify.playlist_items_manual(pid.clone(), None, None, Some(lim), Some(off)))
+        })
+        .await?
+    };
 
-    let tracks: Vec<Value> = playlist
-        .tracks
-        .items
+    let tracks: Vec<Value> = items
         .iter()
         .filter_map(|item| {
             item.track.as_ref().map(|playable| match playable {
-                rspotify::model::PlayableItem::Track(track) => {
-                    let artists: Vec<&str> =
-                        track.artists.iter().map(|a| a.name.as_str()).collect();
-                    json!({
-                        "type": "track",
-                        "id": track.id.as_ref().map(|id| id.to_string()),
-                        "name": track.name,
-                        "artists": artists,
-                        "album": track.album.name,
-                        "duration_ms": track.duration.num_milliseconds(),
-                    })
-                }
+                rspotify::model::PlayableItem::Track(track) => track_json(track),
                 rspotify::model::PlayableItem::Episode(episode) => {
                     json!({
                         "type": "episode",
@@ -520,48 +847,219 @@ async fn cmd_playlist_info(spotify: &AuthCodeSpotify, playlist_id_str: &str) {
         "total_tracks": playlist.tracks.total,
         "tracks": tracks,
     }));
+
+    Ok(())
 }
 
-async fn cmd_playlist_add(spotify: &AuthCodeSpotify, playlist_id_str: &str, track_strs: &[String]) {
-    let playlist_id = PlaylistId::from_id_or_uri(playlist_id_str).unwrap();
+async fn cmd_playlist_add(
+    spotify: &AuthCodeSpotify,
+    playlist_id_str: &str,
+    track_strs: &[String],
+) -> Result<(), SporesError> {
+    let playlist_ref = normalize_ref(playlist_id_str, "playlist")?;
+    let playlist_id = PlaylistId::from_id_or_uri(&playlist_ref)?;
 
-    let track_ids: Vec<TrackId<'_>> = track_strs
+    let track_refs: Vec<String> = track_strs
         .iter()
-        .map(|t| TrackId::from_id_or_uri(t).unwrap())
-        .collect();
+        .map(|t| normalize_ref(t, "track"))
+        .collect::<Result<_, _>>()?;
+    let track_ids: Vec<TrackId<'_>> = track_refs
+        .iter()
+        .map(|t| TrackId::from_id_or_uri(t))
+        .collect::<Result<_, _>>()?;
 
     let playable_ids: Vec<rspotify::model::PlayableId<'_>> = track_ids
         .iter()
         .map(|id| rspotify::model::PlayableId::Track(id.clone()))
         .collect();
 
-    let result = spotify
-        .playlist_add_items(playlist_id, playable_ids, None)
-        .await
-        .unwrap();
+    let result =
+        with_retry(|| spotify.playlist_add_items(playlist_id.clone(), playable_ids.clone(), None))
+            .await?;
 
     print_json(&json!({
         "playlist": playlist_id_str,
         "added": track_strs.len(),
         "snapshot_id": result.snapshot_id,
     }));
+
+    Ok(())
+}
+
+async fn cmd_playlist_compare(
+    spotify: &AuthCodeSpotify,
+    playlist_strs: &[String],
+    op: SetOp,
+    into: Option<&str>,
+) -> Result<(), SporesError> {
+    // Fetch every playlist's full track list, recording each track's id in first-seen
+    // order alongside a bit of metadata for the output.
+    let mut order: Vec<String> = Vec::new();
+    let mut meta: std::collections::HashMap<String, Value> = std::collections::HashMap::new();
+    let mut sets: Vec<std::collections::HashSet<String>> = Vec::new();
+
+    for playlist_str in playlist_strs {
+        let playlist_ref = normalize_ref(playlist_str, "playlist")?;
+        let playlist_id = PlaylistId::from_id_or_uri(&playlist_ref)?;
+        let mut set = std::collections::HashSet::new();
+
+        let items = collect_pages(100, |lim, off| {
+            let pid = playlist_id.clone();
+            with_retry(move || spotify.playlist_items_manual(pid.clone(), None, None, Some(lim), Some(off)))
+        })
+        .await?;
+
+        for item in &items {
+            if let Some(rspotify::model::PlayableItem::Track(track)) = &item.track {
+                if let Some(id) = &track.id {
+                    let key = id.to_string();
+                    if meta.insert(key.clone(), track_json(track)).is_none() {
+                        order.push(key.clone());
+                    }
+                    set.insert(key);
+                }
+            }
+        }
+
+        sets.push(set);
+    }
+
+    // `order` is first-seen across all playlists; restrict to the first playlist's
+    // order for intersect/difference so the base set drives the output.
+    let result_keys: Vec<String> = order
+        .into_iter()
+        .filter(|key| match op {
+            SetOp::Union => sets.iter().any(|s| s.contains(key)),
+            SetOp::Intersect => sets.iter().all(|s| s.contains(key)),
+            SetOp::Difference => {
+                sets[0].contains(key) && sets[1..].iter().all(|s| !s.contains(key))
+            }
+        })
+        .collect();
+
+    let tracks: Vec<Value> = result_keys
+        .iter()
+        .filter_map(|key| meta.get(key).cloned())
+        .collect();
+
+    let into_result = if let Some(name) = into {
+        let user = with_retry(|| spotify.current_user()).await?;
+        let user_id = UserId::from_id(user.id.id())?;
+        let playlist = with_retry(|| {
+            spotify.user_playlist_create(user_id.clone(), name, Some(false), None, None)
+        })
+        .await?;
+
+        // Spotify caps playlist_add_items at 100 tracks per call.
+        for chunk in result_keys.chunks(100) {
+            let playable_ids: Vec<rspotify::model::PlayableId<'_>> = chunk
+                .iter()
+                .map(|key| Ok(rspotify::model::PlayableId::Track(TrackId::from_id_or_uri(key)?)))
+                .collect::<Result<_, SporesError>>()?;
+            with_retry(|| {
+                spotify.playlist_add_items(playlist.id.clone(), playable_ids.clone(), None)
+            })
+            .await?;
+        }
+
+        Some(json!({
+            "id": playlist.id.to_string(),
+            "name": playlist.name,
+            "url": playlist.external_urls.get("spotify"),
+        }))
+    } else {
+        None
+    };
+
+    print_json(&json!({
+        "op": match op {
+            SetOp::Union => "union",
+            SetOp::Intersect => "intersect",
+            SetOp::Difference => "difference",
+        },
+        "playlists": playlist_strs,
+        "total": tracks.len(),
+        "tracks": tracks,
+        "into": into_result,
+    }));
+
+    Ok(())
+}
+
+async fn cmd_playlist_remove(
+    spotify: &AuthCodeSpotify,
+    playlist_id_str: &str,
+    track_strs: &[String],
+) -> Result<(), SporesError> {
+    let playlist_ref = normalize_ref(playlist_id_str, "playlist")?;
+    let playlist_id = PlaylistId::from_id_or_uri(&playlist_ref)?;
+
+    let track_refs: Vec<String> = track_strs
+        .iter()
+        .map(|t| normalize_ref(t, "track"))
+        .collect::<Result<_, _>>()?;
+    let track_ids: Vec<TrackId<'_>> = track_refs
+        .iter()
+        .map(|t| TrackId::from_id_or_uri(t))
+        .collect::<Result<_, _>>()?;
+
+    let playable_ids: Vec<rspotify::model::PlayableId<'_>> = track_ids
+        .iter()
+        .map(|id| rspotify::model::PlayableId::Track(id.clone()))
+        .collect();
+
+    let result = with_retry(|| {
+        spotify.playlist_remove_all_occurrences_of_items(
+            playlist_id.clone(),
+            playable_ids.clone(),
+            None,
+        )
+    })
+    .await?;
+
+    print_json(&json!({
+        "playlist": playlist_id_str,
+        "removed": track_strs.len(),
+        "snapshot_id": result.snapshot_id,
+    }));
+
+    Ok(())
+}
+
+/// Render a [`FullTrack`](rspotify::model::FullTrack) as the JSON object used throughout
+/// the playlist commands.
+fn track_json(track: &rspotify::model::FullTrack) -> Value {
+    let artists: Vec<&str> = track.artists.iter().map(|a| a.name.as_str()).collect();
+    json!({
+        "type": "track",
+        "id": track.id.as_ref().map(|id| id.to_string()),
+        "name": track.name,
+        "artists": artists,
+        "album": track.album.name,
+        "duration_ms": track.duration.num_milliseconds(),
+    })
 }
 
 // ---------------------------------------------------------------------------
 // Save to library
 // ---------------------------------------------------------------------------
 
-async fn cmd_save(spotify: &AuthCodeSpotify, item_type: &ItemType, ids: &[String]) {
+async fn cmd_save(
+    spotify: &AuthCodeSpotify,
+    item_type: &ItemType,
+    ids: &[String],
+) -> Result<(), SporesError> {
     match item_type {
         ItemType::Track => {
-            let track_ids: Vec<TrackId<'_>> = ids
+            let refs: Vec<String> = ids
                 .iter()
-                .map(|id| TrackId::from_id_or_uri(id).unwrap())
-                .collect();
-            spotify
-                .current_user_saved_tracks_add(track_ids)
-                .await
-                .unwrap();
+                .map(|id| normalize_ref(id, item_type.as_url_segment()))
+                .collect::<Result<_, _>>()?;
+            let track_ids: Vec<TrackId<'_>> = refs
+                .iter()
+                .map(|id| TrackId::from_id_or_uri(id))
+                .collect::<Result<_, _>>()?;
+            with_retry(|| spotify.current_user_saved_tracks_add(track_ids.clone())).await?;
             print_json(&json!({
                 "type": "track",
                 "saved": ids.len(),
@@ -569,14 +1067,15 @@ async fn cmd_save(spotify: &AuthCodeSpotify, item_type: &ItemType, ids: &[String
             }));
         }
         ItemType::Album => {
-            let album_ids: Vec<AlbumId<'_>> = ids
+            let refs: Vec<String> = ids
                 .iter()
-                .map(|id| AlbumId::from_id_or_uri(id).unwrap())
-                .collect();
-            spotify
-                .current_user_saved_albums_add(album_ids)
-                .await
-                .unwrap();
+                .map(|id| normalize_ref(id, item_type.as_url_segment()))
+                .collect::<Result<_, _>>()?;
+            let album_ids: Vec<AlbumId<'_>> = refs
+                .iter()
+                .map(|id| AlbumId::from_id_or_uri(id))
+                .collect::<Result<_, _>>()?;
+            with_retry(|| spotify.current_user_saved_albums_add(album_ids.clone())).await?;
             print_json(&json!({
                 "type": "album",
                 "saved": ids.len(),
@@ -585,8 +1084,9 @@ async fn cmd_save(spotify: &AuthCodeSpotify, item_type: &ItemType, ids: &[String
         }
         ItemType::Playlist => {
             for id in ids {
-                let playlist_id = PlaylistId::from_id_or_uri(id).unwrap();
-                spotify.playlist_follow(playlist_id, None).await.unwrap();
+                let playlist_ref = normalize_ref(id, item_type.as_url_segment())?;
+                let playlist_id = PlaylistId::from_id_or_uri(&playlist_ref)?;
+                with_retry(|| spotify.playlist_follow(playlist_id.clone(), None)).await?;
             }
             print_json(&json!({
                 "type": "playlist",
@@ -600,6 +1100,132 @@ async fn cmd_save(spotify: &AuthCodeSpotify, item_type: &ItemType, ids: &[String
             }));
         }
     }
+
+    Ok(())
+}
+
+async fn cmd_unsave(
+    spotify: &AuthCodeSpotify,
+    item_type: &ItemType,
+    ids: &[String],
+) -> Result<(), SporesError> {
+    match item_type {
+        ItemType::Track => {
+            let refs: Vec<String> = ids
+                .iter()
+                .map(|id| normalize_ref(id, item_type.as_url_segment()))
+                .collect::<Result<_, _>>()?;
+            let track_ids: Vec<TrackId<'_>> = refs
+                .iter()
+                .map(|id| TrackId::from_id_or_uri(id))
+                .collect::<Result<_, _>>()?;
+            with_retry(|| spotify.current_user_saved_tracks_delete(track_ids.clone())).await?;
+            print_json(&json!({
+                "type": "track",
+                "removed": ids.len(),
+                "ids": ids,
+            }));
+        }
+        ItemType::Album => {
+            let refs: Vec<String> = ids
+                .iter()
+                .map(|id| normalize_ref(id, item_type.as_url_segment()))
+                .collect::<Result<_, _>>()?;
+            let album_ids: Vec<AlbumId<'_>> = refs
+                .iter()
+                .map(|id| AlbumId::from_id_or_uri(id))
+                .collect::<Result<_, _>>()?;
+            with_retry(|| spotify.current_user_saved_albums_delete(album_ids.clone())).await?;
+            print_json(&json!({
+                "type": "album",
+                "removed": ids.len(),
+                "ids": ids,
+            }));
+        }
+        ItemType::Playlist => {
+            for id in ids {
+                let playlist_ref = normalize_ref(id, item_type.as_url_segment())?;
+                let playlist_id = PlaylistId::from_id_or_uri(&playlist_ref)?;
+                with_retry(|| spotify.playlist_unfollow(playlist_id.clone())).await?;
+            }
+            print_json(&json!({
+                "type": "playlist",
+                "removed": ids.len(),
+                "ids": ids,
+            }));
+        }
+        ItemType::Artist => {
+            return Err(SporesError::Input(
+                "unsaving artists is not supported; use 'unfollow' instead".to_string(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+// ---------------------------------------------------------------------------
+// Follow / unfollow
+// ---------------------------------------------------------------------------
+
+async fn cmd_follow(
+    spotify: &AuthCodeSpotify,
+    follow_type: &FollowType,
+    ids: &[String],
+    follow: bool,
+) -> Result<(), SporesError> {
+    match follow_type {
+        FollowType::Artist => {
+            let refs: Vec<String> = ids
+                .iter()
+                .map(|id| normalize_ref(id, follow_type.as_url_segment()))
+                .collect::<Result<_, _>>()?;
+            let artist_ids: Vec<ArtistId<'_>> = refs
+                .iter()
+                .map(|id| ArtistId::from_id_or_uri(id))
+                .collect::<Result<_, _>>()?;
+            if follow {
+                with_retry(|| spotify.user_follow_artists(artist_ids.clone())).await?;
+            } else {
+                with_retry(|| spotify.user_unfollow_artists(artist_ids.clone())).await?;
+            }
+        }
+        FollowType::User => {
+            let refs: Vec<String> = ids
+                .iter()
+                .map(|id| normalize_ref(id, follow_type.as_url_segment()))
+                .collect::<Result<_, _>>()?;
+            let user_ids: Vec<UserId<'_>> = refs
+                .iter()
+                .map(|id| UserId::from_id_or_uri(id))
+                .collect::<Result<_, _>>()?;
+            if follow {
+                with_retry(|| spotify.user_follow_users(user_ids.clone())).await?;
+            } else {
+                with_retry(|| spotify.user_unfollow_users(user_ids.clone())).await?;
+            }
+        }
+        FollowType::Playlist => {
+            for id in ids {
+                let playlist_ref = normalize_ref(id, follow_type.as_url_segment())?;
+                let playlist_id = PlaylistId::from_id_or_uri(&playlist_ref)?;
+                if follow {
+                    with_retry(|| spotify.playlist_follow(playlist_id.clone(), None)).await?;
+                } else {
+                    with_retry(|| spotify.playlist_unfollow(playlist_id.clone())).await?;
+                }
+            }
+        }
+    }
+
+    print_json(&json!({
+        "action": if follow { "follow" } else { "unfollow" },
+        "type": follow_type.as_url_segment(),
+        "count": ids.len(),
+        "ids": ids,
+    }));
+
+    Ok(())
 }
 
 // ---------------------------------------------------------------------------
@@ -609,14 +1235,26 @@ async fn cmd_save(spotify: &AuthCodeSpotify, item_type: &ItemType, ids: &[String
 #[tokio::main]
 async fn main() {
     let cli = Cli::parse();
+    let json_errors = cli.json;
+
+    if let Err(e) = run(cli).await {
+        if json_errors {
+            print_json(&json!({ "error": e.to_string() }));
+        } else {
+            eprintln!("error: {e}");
+        }
+        process::exit(1);
+    }
+}
 
+async fn run(cli: Cli) -> Result<(), SporesError> {
     // Configure does not require authentication.
     if let Command::Configure = &cli.command {
         cmd_configure();
-        return;
+        return Ok(());
     }
 
-    let spotify = authenticate().await;
+    let spotify = authenticate().await?;
 
     match &cli.command {
         Command::Search {
@@ -631,12 +1269,27 @@ async fn main() {
                 public,
                 description,
             } => cmd_playlist_create(&spotify, name, *public, description.as_deref()).await,
-            PlaylistCommand::Info { playlist } => cmd_playlist_info(&spotify, playlist).await,
+            PlaylistCommand::Info {
+                playlist,
+                limit,
+                offset,
+            } => cmd_playlist_info(&spotify, playlist, *limit, *offset).await,
             PlaylistCommand::Add { playlist, tracks } => {
                 cmd_playlist_add(&spotify, playlist, tracks).await
             }
+            PlaylistCommand::Compare {
+                playlists,
+                op,
+                into,
+            } => cmd_playlist_compare(&spotify, playlists, *op, into.as_deref()).await,
+            PlaylistCommand::Remove { playlist, tracks } => {
+                cmd_playlist_remove(&spotify, playlist, tracks).await
+            }
         },
         Command::Configure => unreachable!(),
         Command::Save { r#type, ids } => cmd_save(&spotify, r#type, ids).await,
+        Command::Unsave { r#type, ids } => cmd_unsave(&spotify, r#type, ids).await,
+        Command::Follow { r#type, ids } => cmd_follow(&spotify, r#type, ids, true).await,
+        Command::Unfollow { r#type, ids } => cmd_follow(&spotify, r#type, ids, false).await,
     }
 }